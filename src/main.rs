@@ -1,43 +1,651 @@
-use clap::Parser;
-use dialoguer::Input;
+use clap::{Parser, ValueEnum};
+use dialoguer::{Input, MultiSelect, Select};
 use indicatif::{ProgressBar, ProgressStyle};
 use regex::Regex;
+use std::ffi::OsStr;
+use std::fmt;
 use std::fs::{self, OpenOptions};
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::Path;
-use std::process::{Command, Stdio};
+use std::process::{Command, ExitStatus, Stdio};
 use std::time::Duration;
 
+/// Builds the `Command` for `tool`, appending the `.cmd` extension on
+/// Windows since the real package-manager binaries there are `.cmd` shims
+/// that `Command::new` can't launch directly. This avoids routing through
+/// `cmd /C`, which would re-tokenize argv (including user-supplied values
+/// like the app name) and open the door to shell injection.
+fn command_for(tool: &str) -> Command {
+    if cfg!(windows) {
+        // Node-based tools (npm/pnpm/yarn) are installed as `.cmd` shims on
+        // Windows, but Bun ships as a standalone `bun.exe` with no shim.
+        if tool == "bun" {
+            Command::new(tool)
+        } else {
+            Command::new(format!("{}.cmd", tool))
+        }
+    } else {
+        Command::new(tool)
+    }
+}
+
+/// The result of a `run()` call: the process status (or spawn error) plus,
+/// in quiet mode, the captured stderr so a failure can be diagnosed without
+/// re-running by hand.
+struct RunOutcome {
+    status: io::Result<ExitStatus>,
+    stderr: Option<String>,
+}
+
+impl RunOutcome {
+    fn success(&self) -> bool {
+        matches!(self.status, Ok(ref status) if status.success())
+    }
+
+    /// Prints the last `TAIL_LINES` lines of the captured stderr, if any was
+    /// captured.
+    fn print_captured_stderr(&self) {
+        const TAIL_LINES: usize = 20;
+
+        if let Some(stderr) = &self.stderr {
+            let lines: Vec<&str> = stderr.trim().lines().collect();
+            let tail = lines[lines.len().saturating_sub(TAIL_LINES)..].join("\n");
+            if !tail.is_empty() {
+                eprintln!("{}", tail);
+            }
+        }
+    }
+}
+
+/// Runs `tool` with `args` in `dir` (or the current directory). The single
+/// place every external process is spawned from, so logging and error
+/// context only need to be added here.
+///
+/// In verbose mode, child stdout/stderr stream straight to the terminal
+/// (with `spinner` paused so they don't interleave with the tick
+/// animation). In quiet mode stdio is suppressed, but stderr is captured so
+/// callers can surface it on failure.
+fn run<S: AsRef<OsStr>>(
+    tool: &str,
+    args: &[S],
+    dir: Option<&Path>,
+    verbose: bool,
+    spinner: &ProgressBar,
+) -> RunOutcome {
+    let mut command = command_for(tool);
+    command.args(args);
+
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+
+    if verbose {
+        let status = spinner.suspend(|| {
+            command
+                .stdin(Stdio::null())
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status()
+        });
+
+        RunOutcome {
+            status,
+            stderr: None,
+        }
+    } else {
+        let output = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output();
+
+        match output {
+            Ok(output) => RunOutcome {
+                status: Ok(output.status),
+                stderr: Some(String::from_utf8_lossy(&output.stderr).into_owned()),
+            },
+            Err(err) => RunOutcome {
+                status: Err(err),
+                stderr: None,
+            },
+        }
+    }
+}
+
 #[derive(Parser)]
 #[command(author, version, about)]
 struct Args {
-    /// Name of the React app to create
+    /// Name of the app to create
     name: Option<String>,
+
+    /// Package manager to use for scaffolding and installing dependencies
+    #[arg(short = 'p', long, value_enum)]
+    package_manager: Option<PackageManager>,
+
+    /// Scaffold with TypeScript (the framework's `-ts` Vite template, e.g. react-ts)
+    #[arg(long)]
+    typescript: bool,
+
+    /// Install and configure ESLint
+    #[arg(long)]
+    eslint: bool,
+
+    /// Install and configure Prettier
+    #[arg(long)]
+    prettier: bool,
+
+    /// Install and configure Vitest
+    #[arg(long)]
+    vitest: bool,
+
+    /// Install and configure Playwright
+    #[arg(long)]
+    playwright: bool,
+
+    /// Tailwind CSS major version to configure
+    #[arg(long, value_enum, default_value_t = TailwindVersion::V4)]
+    tailwind_version: TailwindVersion,
+
+    /// Frontend framework to scaffold
+    #[arg(long, value_enum, default_value_t = Framework::React)]
+    framework: Framework,
+
+    /// Namespace all Tailwind utility classes under this prefix (e.g. `mylib`
+    /// produces `mylib:flex` with Tailwind v4, and `mylibflex` with v3, where
+    /// the prefix is prepended as-is), for generating component libraries
+    /// that won't clash with a consuming app's Tailwind build
+    #[arg(long)]
+    prefix: Option<String>,
+
+    /// Stream child process output instead of suppressing it
+    #[arg(long)]
+    verbose: bool,
+}
+
+/// Which Tailwind CSS generation to configure, since v3 and v4 use
+/// incompatible setup steps (PostCSS config vs. the Vite plugin).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum TailwindVersion {
+    #[value(name = "3")]
+    V3,
+    #[value(name = "4")]
+    V4,
+}
+
+/// A frontend framework the Vite scaffold can target. Each one needs its
+/// own Vite template, CSS entry file, and (for Tailwind v3) content glob.
+#[derive(Copy, Clone, Debug, ValueEnum, PartialEq, Eq)]
+enum Framework {
+    React,
+    Vue,
+    Svelte,
+    Solid,
+    Preact,
+}
+
+impl Framework {
+    fn vite_template(&self, typescript: bool) -> &'static str {
+        match (self, typescript) {
+            (Framework::React, false) => "react",
+            (Framework::React, true) => "react-ts",
+            (Framework::Vue, false) => "vue",
+            (Framework::Vue, true) => "vue-ts",
+            (Framework::Svelte, false) => "svelte",
+            (Framework::Svelte, true) => "svelte-ts",
+            (Framework::Solid, false) => "solid",
+            (Framework::Solid, true) => "solid-ts",
+            (Framework::Preact, false) => "preact",
+            (Framework::Preact, true) => "preact-ts",
+        }
+    }
+
+    /// Path (relative to the app root) of the CSS file Tailwind is wired into.
+    fn css_entry(&self) -> &'static str {
+        match self {
+            Framework::Vue => "src/style.css",
+            Framework::Svelte => "src/app.css",
+            Framework::React | Framework::Solid | Framework::Preact => "src/index.css",
+        }
+    }
+
+    /// Extra file extension this framework's components use, beyond the
+    /// shared `js/jsx/ts/tsx` glob, for the Tailwind v3 content list.
+    fn content_extension(&self) -> Option<&'static str> {
+        match self {
+            Framework::Vue => Some("vue"),
+            Framework::Svelte => Some("svelte"),
+            Framework::React | Framework::Solid | Framework::Preact => None,
+        }
+    }
+
+    /// Globs for `tailwind.config.js`'s `content` array in v3 mode.
+    fn content_globs(&self) -> Vec<String> {
+        let mut globs = vec![
+            "./index.html".to_string(),
+            "./src/**/*.{js,jsx,ts,tsx}".to_string(),
+        ];
+
+        if let Some(ext) = self.content_extension() {
+            globs.push(format!("./src/**/*.{}", ext));
+        }
+
+        globs
+    }
+}
+
+impl fmt::Display for TailwindVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TailwindVersion::V3 => write!(f, "3"),
+            TailwindVersion::V4 => write!(f, "4"),
+        }
+    }
+}
+
+impl fmt::Display for Framework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Framework::React => write!(f, "react"),
+            Framework::Vue => write!(f, "vue"),
+            Framework::Svelte => write!(f, "svelte"),
+            Framework::Solid => write!(f, "solid"),
+            Framework::Preact => write!(f, "preact"),
+        }
+    }
+}
+
+/// A supported JS package manager, used to route every scaffold/install/add
+/// step through the invocation that manager expects.
+#[derive(Copy, Clone, Debug, ValueEnum)]
+enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManager {
+    const ALL: [PackageManager; 4] = [
+        PackageManager::Npm,
+        PackageManager::Pnpm,
+        PackageManager::Yarn,
+        PackageManager::Bun,
+    ];
+
+    fn binary(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Yarn => "yarn",
+            PackageManager::Bun => "bun",
+        }
+    }
+
+    /// Args for `<pm> create vite <app_name> [--] --template <template>`.
+    /// Only npm needs the `create` pass-through `--` separator; pnpm, yarn,
+    /// and bun forward flags to `create-vite` directly.
+    fn create_vite_args(&self, app_name: &str, template: &str) -> Vec<String> {
+        let app_name = app_name.to_string();
+        let template = template.to_string();
+
+        match self {
+            PackageManager::Npm => vec![
+                "create".into(),
+                "vite@latest".into(),
+                app_name,
+                "--".into(),
+                "--template".into(),
+                template,
+            ],
+            PackageManager::Pnpm | PackageManager::Yarn | PackageManager::Bun => vec![
+                "create".into(),
+                "vite".into(),
+                app_name,
+                "--template".into(),
+                template,
+            ],
+        }
+    }
+
+    fn install_args(&self) -> Vec<&'static str> {
+        vec!["install"]
+    }
+
+    /// Args for adding dev dependencies, e.g. `npm install -D <pkgs>` vs
+    /// `pnpm add -D <pkgs>` vs `bun add -d <pkgs>`.
+    fn add_dev_args<'a>(&self, packages: &[&'a str]) -> Vec<&'a str> {
+        let mut args = match self {
+            PackageManager::Npm => vec!["install", "-D"],
+            PackageManager::Pnpm => vec!["add", "-D"],
+            PackageManager::Yarn => vec!["add", "-D"],
+            PackageManager::Bun => vec!["add", "-d"],
+        };
+
+        args.extend_from_slice(packages);
+        args
+    }
 }
 
-fn setup_tailwind(app_path: &Path, spinner: &ProgressBar) {
+impl fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.binary())
+    }
+}
+
+fn prompt_package_manager() -> PackageManager {
+    let options = PackageManager::ALL;
+    let labels: Vec<String> = options.iter().map(|pm| pm.to_string()).collect();
+    let selection = Select::new()
+        .with_prompt("Select a package manager")
+        .items(&labels)
+        .default(0)
+        .interact()
+        .unwrap_or(0);
+
+    options[selection]
+}
+
+/// An optional project feature offered in the feature-selection menu.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Feature {
+    TypeScript,
+    Eslint,
+    Prettier,
+    Vitest,
+    Playwright,
+}
+
+impl Feature {
+    const ALL: [Feature; 5] = [
+        Feature::TypeScript,
+        Feature::Eslint,
+        Feature::Prettier,
+        Feature::Vitest,
+        Feature::Playwright,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Feature::TypeScript => "TypeScript",
+            Feature::Eslint => "ESLint",
+            Feature::Prettier => "Prettier",
+            Feature::Vitest => "Vitest",
+            Feature::Playwright => "Playwright",
+        }
+    }
+}
+
+/// Which optional features to scaffold, resolved either from CLI flags or
+/// the interactive feature-selection menu.
+struct SelectedFeatures {
+    typescript: bool,
+    eslint: bool,
+    prettier: bool,
+    vitest: bool,
+    playwright: bool,
+}
+
+impl SelectedFeatures {
+    fn from_args(args: &Args) -> Self {
+        SelectedFeatures {
+            typescript: args.typescript,
+            eslint: args.eslint,
+            prettier: args.prettier,
+            vitest: args.vitest,
+            playwright: args.playwright,
+        }
+    }
+
+    fn any_set(args: &Args) -> bool {
+        args.typescript || args.eslint || args.prettier || args.vitest || args.playwright
+    }
+}
+
+fn prompt_features() -> SelectedFeatures {
+    let labels: Vec<&str> = Feature::ALL.iter().map(|feature| feature.label()).collect();
+    let selections = MultiSelect::new()
+        .with_prompt("Select additional features (space to toggle, enter to confirm)")
+        .items(&labels)
+        .interact()
+        .unwrap_or_default();
+
+    let chosen: Vec<Feature> = selections.into_iter().map(|i| Feature::ALL[i]).collect();
+
+    SelectedFeatures {
+        typescript: chosen.contains(&Feature::TypeScript),
+        eslint: chosen.contains(&Feature::Eslint),
+        prettier: chosen.contains(&Feature::Prettier),
+        vitest: chosen.contains(&Feature::Vitest),
+        playwright: chosen.contains(&Feature::Playwright),
+    }
+}
+
+fn install_eslint(
+    app_path: &Path,
+    package_manager: PackageManager,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    spinner.set_message("Installing ESLint...");
+
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["eslint"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to install ESLint.");
+        install.print_captured_stderr();
+        std::process::exit(1);
+    }
+
+    let config = "export default [\n  {\n    ignores: ['dist/**'],\n  },\n];\n";
+
+    if fs::write(app_path.join("eslint.config.js"), config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write eslint.config.js.");
+        std::process::exit(1);
+    }
+
+    println!("✅ ESLint configured.");
+}
+
+fn install_prettier(
+    app_path: &Path,
+    package_manager: PackageManager,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    spinner.set_message("Installing Prettier...");
+
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["prettier"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to install Prettier.");
+        install.print_captured_stderr();
+        std::process::exit(1);
+    }
+
+    let config = "{\n  \"semi\": true,\n  \"singleQuote\": true\n}\n";
+
+    if fs::write(app_path.join(".prettierrc"), config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write .prettierrc.");
+        std::process::exit(1);
+    }
+
+    println!("✅ Prettier configured.");
+}
+
+fn install_vitest(
+    app_path: &Path,
+    package_manager: PackageManager,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    spinner.set_message("Installing Vitest...");
+
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["vitest", "jsdom"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to install Vitest.");
+        install.print_captured_stderr();
+        std::process::exit(1);
+    }
+
+    let config = "import { defineConfig } from 'vitest/config';\n\nexport default defineConfig({\n  test: {\n    environment: 'jsdom',\n  },\n});\n";
+
+    if fs::write(app_path.join("vitest.config.ts"), config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write vitest.config.ts.");
+        std::process::exit(1);
+    }
+
+    println!("✅ Vitest configured.");
+}
+
+fn install_playwright(
+    app_path: &Path,
+    package_manager: PackageManager,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    spinner.set_message("Installing Playwright...");
+
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["@playwright/test"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to install Playwright.");
+        install.print_captured_stderr();
+        std::process::exit(1);
+    }
+
+    let config = "import { defineConfig } from '@playwright/test';\n\nexport default defineConfig({\n  testDir: './e2e',\n});\n";
+
+    if fs::write(app_path.join("playwright.config.ts"), config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write playwright.config.ts.");
+        std::process::exit(1);
+    }
+
+    println!("✅ Playwright configured.");
+}
+
+fn setup_features(
+    app_path: &Path,
+    package_manager: PackageManager,
+    features: &SelectedFeatures,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    if features.eslint {
+        install_eslint(app_path, package_manager, verbose, spinner);
+    }
+
+    if features.prettier {
+        install_prettier(app_path, package_manager, verbose, spinner);
+    }
+
+    if features.vitest {
+        install_vitest(app_path, package_manager, verbose, spinner);
+    }
+
+    if features.playwright {
+        install_playwright(app_path, package_manager, verbose, spinner);
+    }
+}
+
+/// Rejects a `--prefix` that would break out of the string literals it gets
+/// spliced into in the generated `tailwind.config.js` / CSS `@import`.
+fn validate_prefix(prefix: &str) {
+    let valid = !prefix.is_empty()
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    if !valid {
+        eprintln!(
+            "❌ Invalid --prefix '{}': only letters, digits, '_' and '-' are allowed.",
+            prefix
+        );
+        std::process::exit(1);
+    }
+}
+
+fn setup_tailwind(
+    app_path: &Path,
+    package_manager: PackageManager,
+    tailwind_version: TailwindVersion,
+    framework: Framework,
+    prefix: Option<&str>,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
     spinner.set_style(
         ProgressStyle::with_template("{spinner} {msg}")
             .unwrap()
             .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
     );
     spinner.enable_steady_tick(Duration::from_millis(100));
+
+    match tailwind_version {
+        TailwindVersion::V4 => {
+            setup_tailwind_v4(app_path, package_manager, framework, prefix, verbose, spinner)
+        }
+        TailwindVersion::V3 => {
+            setup_tailwind_v3(app_path, package_manager, framework, prefix, verbose, spinner)
+        }
+    }
+}
+
+/// Tailwind v4: the `@tailwindcss/vite` plugin plus a single `@import`.
+fn setup_tailwind_v4(
+    app_path: &Path,
+    package_manager: PackageManager,
+    framework: Framework,
+    prefix: Option<&str>,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
     spinner.set_message("Installing TailwindCSS...");
 
-    let install = Command::new("npm")
-        .arg("install")
-        .arg("-D")
-        .arg("tailwindcss")
-        .arg("@tailwindcss/vite")
-        .current_dir(app_path)
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .status();
-
-    if !matches!(install, Ok(s) if s.success()) {
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["tailwindcss", "@tailwindcss/vite"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
         spinner.finish_and_clear();
         eprintln!("❌ Failed to install TailwindCSS.");
+        install.print_captured_stderr();
         std::process::exit(1);
     }
 
@@ -74,13 +682,16 @@ fn setup_tailwind(app_path: &Path, spinner: &ProgressBar) {
         }
     }
 
-    // Write src/index.css
-    let css_path = app_path.join("src").join("index.css");
-    let tailwind_css = "@tailwind base;\n@tailwind components;\n@tailwind utilities;\n";
+    // Write the framework's CSS entry file
+    let css_path = app_path.join(framework.css_entry());
+    let tailwind_css = match prefix {
+        Some(prefix) => format!("@import \"tailwindcss\" prefix({});\n", prefix),
+        None => "@import \"tailwindcss\";\n".to_string(),
+    };
 
-    if fs::write(css_path, tailwind_css).is_err() {
+    if fs::write(&css_path, tailwind_css).is_err() {
         spinner.finish_and_clear();
-        eprintln!("❌ Failed to write src/index.css.");
+        eprintln!("❌ Failed to write {}.", framework.css_entry());
         std::process::exit(1);
     }
 
@@ -88,13 +699,84 @@ fn setup_tailwind(app_path: &Path, spinner: &ProgressBar) {
     println!("✅ TailwindCSS with Vite plugin configured.");
 }
 
+/// Tailwind v3: the classic PostCSS pipeline with a config file and the
+/// three `@tailwind` directives.
+fn setup_tailwind_v3(
+    app_path: &Path,
+    package_manager: PackageManager,
+    framework: Framework,
+    prefix: Option<&str>,
+    verbose: bool,
+    spinner: &ProgressBar,
+) {
+    spinner.set_message("Installing TailwindCSS...");
+
+    let install = run(
+        package_manager.binary(),
+        &package_manager.add_dev_args(&["tailwindcss", "postcss", "autoprefixer"]),
+        Some(app_path),
+        verbose,
+        spinner,
+    );
+
+    if !install.success() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to install TailwindCSS.");
+        install.print_captured_stderr();
+        std::process::exit(1);
+    }
+
+    let content = framework
+        .content_globs()
+        .iter()
+        .map(|glob| format!("'{}'", glob))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let prefix_line = match prefix {
+        Some(prefix) => format!("  prefix: '{}',\n", prefix),
+        None => String::new(),
+    };
+    let tailwind_config = format!(
+        "/** @type {{import('tailwindcss').Config}} */\nexport default {{\n  content: [{}],\n{}  theme: {{\n    extend: {{}},\n  }},\n  plugins: [],\n}};\n",
+        content, prefix_line
+    );
+
+    if fs::write(app_path.join("tailwind.config.js"), tailwind_config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write tailwind.config.js.");
+        std::process::exit(1);
+    }
+
+    let postcss_config =
+        "export default {\n  plugins: {\n    tailwindcss: {},\n    autoprefixer: {},\n  },\n};\n";
+
+    if fs::write(app_path.join("postcss.config.js"), postcss_config).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write postcss.config.js.");
+        std::process::exit(1);
+    }
+
+    // Write the framework's CSS entry file
+    let css_path = app_path.join(framework.css_entry());
+    let tailwind_css = "@tailwind base;\n@tailwind components;\n@tailwind utilities;\n";
+
+    if fs::write(&css_path, tailwind_css).is_err() {
+        spinner.finish_and_clear();
+        eprintln!("❌ Failed to write {}.", framework.css_entry());
+        std::process::exit(1);
+    }
+
+    spinner.finish_and_clear();
+    println!("✅ TailwindCSS configured.");
+}
+
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    let app_name = match args.name {
+    let app_name = match args.name.take() {
         Some(name) => name,
         None => Input::new()
-            .with_prompt("Enter your React app name")
+            .with_prompt("Enter your app name")
             .interact_text()
             .unwrap_or_else(|err| {
                 eprintln!("Failed to read input: {}", err);
@@ -102,6 +784,20 @@ fn main() {
             }),
     };
 
+    let package_manager = args.package_manager.unwrap_or_else(prompt_package_manager);
+    let features = if SelectedFeatures::any_set(&args) {
+        SelectedFeatures::from_args(&args)
+    } else {
+        prompt_features()
+    };
+    let template = args.framework.vite_template(features.typescript);
+    let tailwind_version = args.tailwind_version;
+    let prefix = args.prefix.clone();
+    if let Some(prefix) = &prefix {
+        validate_prefix(prefix);
+    }
+    let verbose = args.verbose;
+
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::with_template("{spinner} {msg}")
@@ -112,25 +808,22 @@ fn main() {
 
     spinner.set_message("Creating Vite app...");
 
-    let create_status = Command::new("npm")
-        .arg("create")
-        .arg("vite@latest")
-        .arg(&app_name)
-        .arg("--")
-        .arg("--template")
-        .arg("react")
-        .stdin(Stdio::null())
-        .stdout(Stdio::null()) // suppress stdout
-        .stderr(Stdio::null()) // suppress stderr
-        .status();
-
-    match create_status {
+    let create_status = run(
+        package_manager.binary(),
+        &package_manager.create_vite_args(&app_name, template),
+        None,
+        verbose,
+        &spinner,
+    );
+
+    match create_status.status {
         Ok(code) if code.success() => {
             spinner.set_message("Installing dependencies...");
         }
         Ok(code) => {
             spinner.finish_and_clear();
             eprintln!("❌ App creation failed with exit code: {}", code);
+            create_status.print_captured_stderr();
             std::process::exit(1);
         }
         Err(err) => {
@@ -141,26 +834,40 @@ fn main() {
     }
 
     let app_path = Path::new(&app_name);
-    let install_status = Command::new("npm")
-        .arg("install")
-        .current_dir(app_path)
-        .stdin(Stdio::null())
-        .stdout(Stdio::null()) // suppress stdout
-        .stderr(Stdio::null()) // suppress stderr
-        .status();
+    let install_status = run(
+        package_manager.binary(),
+        &package_manager.install_args(),
+        Some(app_path),
+        verbose,
+        &spinner,
+    );
 
-    setup_tailwind(app_path, &spinner);
+    setup_tailwind(
+        app_path,
+        package_manager,
+        tailwind_version,
+        args.framework,
+        prefix.as_deref(),
+        verbose,
+        &spinner,
+    );
+    setup_features(app_path, package_manager, &features, verbose, &spinner);
 
     spinner.finish_and_clear();
 
-    match install_status {
+    match install_status.status {
         Ok(code) if code.success() => {
-            println!("✅ React app '{}' created successfully!", app_name);
+            println!("✅ App '{}' created successfully!", app_name);
             println!("\n➡️  To get started:\n");
-            println!("  cd {}\n  npm run dev\n", app_name);
+            println!(
+                "  cd {}\n  {} run dev\n",
+                app_name,
+                package_manager.binary()
+            );
         }
         Ok(code) => {
             eprintln!("❌ `npm install` failed with exit code: {}", code);
+            install_status.print_captured_stderr();
             std::process::exit(1);
         }
         Err(err) => {